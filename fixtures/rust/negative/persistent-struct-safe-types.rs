@@ -0,0 +1,17 @@
+// Test file: #[persistent] structs storing only persistent-safe types
+// This should NOT trigger any PSAFE violations
+
+#[persistent]
+struct GoodRegion {
+    head: u64,
+    tail: u64,
+    label: [u8; 16],
+    counter: u64,
+}
+
+// Fields of types that PSAFE1-PSAFE6 ban are never checked outside a
+// #[persistent] region, since the region restricts where the rules apply.
+struct OrdinaryStruct {
+    head: *const u8,
+    label: &'static str,
+}
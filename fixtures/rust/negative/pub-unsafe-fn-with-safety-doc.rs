@@ -0,0 +1,39 @@
+// Test file: public unsafe fns that document their invariants
+// This should NOT trigger N8 violations
+
+/// Reads the value at `ptr` without checking that it is valid.
+///
+/// # Safety
+///
+/// `ptr` must be non-null, aligned, and point to an initialized `i32`
+/// that is valid for reads for the duration of this call.
+pub unsafe fn read_raw(ptr: *const i32) -> i32 {
+    unsafe { *ptr }
+}
+
+/// Block-comment form of the doc comment is also recognized.
+/**
+ * # Safety
+ *
+ * `ptr` must be non-null and valid for reads.
+ */
+pub unsafe fn read_raw_block_doc(ptr: *const i32) -> i32 {
+    unsafe { *ptr }
+}
+
+// Private unsafe fns are exempt from N8 even without a `# Safety` section.
+unsafe fn private_helper(ptr: *const i32) -> i32 {
+    unsafe { *ptr }
+}
+
+// Unsafe trait impls are exempt from N8; the safety contract lives on the
+// trait declaration, not each impl.
+unsafe trait Marker {
+    fn marker(&self);
+}
+
+struct Thing;
+
+unsafe impl Marker for Thing {
+    fn marker(&self) {}
+}
@@ -0,0 +1,25 @@
+// Test file: transmutes into any-bit-pattern-valid targets
+// These still trigger the generic N2 violation, but must NOT be escalated
+// to N9 - every bit pattern of the destination integer/array type is a
+// legal value, so there is no additional soundness hazard beyond "this is
+// unsafe."
+
+use std::mem;
+
+fn into_u32() {
+    let bytes: [u8; 4] = [0, 0, 0, 0];
+    let value: u32 = unsafe { mem::transmute(bytes) };
+    let _ = value;
+}
+
+fn into_byte_array() {
+    let value: u32 = 0;
+    let bytes: [u8; 4] = unsafe { mem::transmute(value) };
+    let _ = bytes;
+}
+
+fn into_array_of_integers() {
+    let value: u64 = 0;
+    let halves: [u32; 2] = unsafe { mem::transmute(value) };
+    let _ = halves;
+}
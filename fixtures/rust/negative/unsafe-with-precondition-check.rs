@@ -0,0 +1,22 @@
+// Test file: unchecked operations guarded by debug_assert!/assert!
+// This should NOT trigger N11 violations
+
+fn deref_with_check(ptr: *const i32) -> i32 {
+    debug_assert!(!ptr.is_null() && ptr.align_offset(align_of::<i32>()) == 0);
+    unsafe { *ptr }
+}
+
+fn offset_with_check(ptr: *const u8, offset: isize, len: isize) -> *const u8 {
+    // The assertion may appear earlier in the same block, not only on the
+    // line immediately above the unchecked operation.
+    assert!(offset >= 0 && offset <= len, "offset out of bounds");
+    let remaining = len - offset;
+    let result = unsafe { ptr.offset(offset) };
+    let _ = remaining;
+    result
+}
+
+fn get_unchecked_with_check(items: &[i32], index: usize) -> i32 {
+    debug_assert!(index < items.len(), "index out of bounds");
+    unsafe { *items.get_unchecked(index) }
+}
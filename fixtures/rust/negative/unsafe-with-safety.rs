@@ -1,10 +1,7 @@
 // Test file: unsafe blocks WITH SAFETY comments
-// This should NOT trigger N1 violation (but with simple AST query it will)
-// Note: The AST query flags ALL unsafe blocks. SAFETY comment checking
-// would require flight-lint enhancement to check sibling nodes.
-
-// For now, this file demonstrates the pattern we want to eventually allow.
-// The current implementation will still flag these as violations.
+// This should NOT trigger N1 violation. N1 walks backward over the
+// comment(s) immediately attached to the unsafe block and suppresses the
+// violation when one begins with `SAFETY:`.
 
 fn safe_usage() {
     // SAFETY: ptr::null returns a valid null pointer that is safe to create
@@ -25,3 +22,20 @@ fn another_safe_usage() {
         let _ = first;
     }
 }
+
+/// Reads the first byte of a non-empty slice without bounds checking.
+unsafe fn read_first_unchecked(bytes: &[u8]) -> u8 {
+    // SAFETY: callers must guarantee `bytes` is non-empty; the comment
+    // belongs inside the fn body, directly above the unsafe block.
+    unsafe { *bytes.get_unchecked(0) }
+}
+
+fn multi_line_safety_comment() {
+    // SAFETY: this is the first line of a longer explanation.
+    // Continuation lines without their own `SAFETY:` prefix are still
+    // part of the same comment and should not break the association.
+    unsafe {
+        let ptr = std::ptr::null::<i32>();
+        let _ = ptr;
+    }
+}
@@ -0,0 +1,22 @@
+// Test file: #[persistent] structs storing types banned by PSAFE1-PSAFE6
+// (see fixtures/config/forbidden-types.example.toml)
+// This should trigger a violation for every field below
+
+use std::cell::UnsafeCell;
+use std::fs::File;
+
+#[persistent]
+struct BadRegion {
+    // PSAFE1: raw pointer
+    head: *const u8,
+    // PSAFE2: raw pointer
+    tail: *mut u8,
+    // PSAFE3: reference
+    label: &'static str,
+    // PSAFE4: UnsafeCell
+    counter: UnsafeCell<u64>,
+    // PSAFE5: file handle
+    log: File,
+    // PSAFE6: function pointer
+    on_recover: fn(u64) -> u64,
+}
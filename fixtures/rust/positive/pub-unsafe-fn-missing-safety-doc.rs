@@ -0,0 +1,19 @@
+// Test file: public unsafe fns missing a `# Safety` doc section
+// This should trigger N8 violations
+
+/// Reads the value at `ptr` without checking that it is valid.
+pub unsafe fn read_raw(ptr: *const i32) -> i32 {
+    unsafe { *ptr }
+}
+
+/// Has documentation, but no `# Safety` heading at all.
+///
+/// Callers should be careful with the pointer they pass in.
+pub unsafe fn read_raw_mut(ptr: *mut i32) -> i32 {
+    unsafe { *ptr }
+}
+
+// No doc comment whatsoever.
+pub unsafe fn read_raw_undocumented(ptr: *const i32) -> i32 {
+    unsafe { *ptr }
+}
@@ -0,0 +1,59 @@
+// Test file: N2 transmutes that the suggestion engine can rewrite
+// Each violation below except the last carries a machine-applicable fix;
+// the comment documents the replacement the engine is expected to suggest.
+// The last one has no known safe alternative and gets only a note.
+
+use std::mem;
+
+fn bytes_to_int() {
+    let bytes: [u8; 4] = [0, 0, 0, 0];
+    // Suggested fix: u32::from_ne_bytes(bytes)
+    let num: u32 = unsafe { mem::transmute(bytes) };
+    let _ = num;
+}
+
+fn int_to_bytes() {
+    let num: u32 = 0;
+    // Suggested fix: num.to_ne_bytes()
+    let bytes: [u8; 4] = unsafe { mem::transmute(num) };
+    let _ = bytes;
+}
+
+fn bits_to_float() {
+    let bits: u32 = 0x3f80_0000;
+    // Suggested fix: f32::from_bits(bits)
+    let value: f32 = unsafe { mem::transmute(bits) };
+    let _ = value;
+}
+
+fn float_to_bits() {
+    let value: f32 = 1.0;
+    // Suggested fix: value.to_bits()
+    let bits: u32 = unsafe { mem::transmute(value) };
+    let _ = bits;
+}
+
+fn bits_to_double() {
+    let bits: u64 = 0x3ff0_0000_0000_0000;
+    // Suggested fix: f64::from_bits(bits)
+    let value: f64 = unsafe { mem::transmute(bits) };
+    let _ = value;
+}
+
+fn reference_cast(x: &i32) {
+    // Suggested fix: use a checked cast instead of reinterpreting the reference
+    let y: &u32 = unsafe { mem::transmute(x) };
+    let _ = y;
+}
+
+fn no_known_alternative() {
+    struct Opaque {
+        a: u8,
+        b: u8,
+        c: u8,
+    }
+    let opaque = Opaque { a: 0, b: 0, c: 0 };
+    // No safe alternative known: arbitrary struct-to-struct transmute
+    let raw: [u8; 3] = unsafe { mem::transmute(opaque) };
+    let _ = raw;
+}
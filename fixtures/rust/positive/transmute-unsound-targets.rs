@@ -0,0 +1,58 @@
+// Test file: transmutes into restricted-bit-pattern types
+// These should trigger N9 (higher severity than the generic N2 violation)
+// because the destination type's validity invariant can be broken by an
+// arbitrary source value, causing immediate UB even before the result is
+// used. The last case, size_mismatch, triggers N10 instead.
+
+use std::mem;
+use std::num::NonZeroU32;
+
+#[repr(u8)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+fn into_bool() {
+    let x: u8 = 5;
+    // N9: not every u8 is a valid bool
+    let y: bool = unsafe { mem::transmute(x) };
+    let _ = y;
+}
+
+fn into_char() {
+    let x: u32 = 0xffff_ffff;
+    // N9: not every u32 is a valid char
+    let y: char = unsafe { mem::transmute(x) };
+    let _ = y;
+}
+
+fn into_reference() {
+    let x: usize = 0;
+    // N9: not every pointer-width bit pattern is a valid, dereferenceable reference
+    let y: &mut bool = unsafe { mem::transmute(x) };
+    let _ = y;
+}
+
+fn into_nonzero() {
+    let x: u32 = 0;
+    // N9: NonZeroU32 can never legally hold zero
+    let y: NonZeroU32 = unsafe { mem::transmute(x) };
+    let _ = y;
+}
+
+fn into_fieldless_enum() {
+    let x: u8 = 200;
+    // N9: fieldless enums only admit their declared discriminants
+    let y: Direction = unsafe { mem::transmute(x) };
+    let _ = y;
+}
+
+fn size_mismatch() {
+    let bytes: [u8; 3] = [0, 0, 0];
+    // N10: source and destination sizes are statically known and differ
+    let value: u32 = unsafe { mem::transmute(bytes) };
+    let _ = value;
+}
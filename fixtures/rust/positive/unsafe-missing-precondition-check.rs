@@ -0,0 +1,17 @@
+// Test file: unchecked operations with no guarding debug_assert!/assert!
+// This should trigger N11 violations (SHOULD-level)
+
+fn deref_without_check(ptr: *const i32) -> i32 {
+    // N11: no assertion that `ptr` is non-null and aligned before deref
+    unsafe { *ptr }
+}
+
+fn offset_without_check(ptr: *const u8, offset: isize) -> *const u8 {
+    // N11: no assertion that the resulting pointer stays in bounds
+    unsafe { ptr.offset(offset) }
+}
+
+fn get_unchecked_without_check(items: &[i32], index: usize) -> i32 {
+    // N11: no assertion that `index` is in bounds
+    unsafe { *items.get_unchecked(index) }
+}
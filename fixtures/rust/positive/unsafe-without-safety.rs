@@ -16,3 +16,21 @@ fn also_risky() {
         let _ = raw;
     }
 }
+
+fn mentions_safety_mid_sentence() {
+    // This block is safety critical, handle with care
+    unsafe {
+        let raw: *const u8 = &7u8;
+        let _ = raw;
+    }
+}
+
+fn comment_not_contiguous() {
+    // SAFETY: this explanation is detached from the block below by
+    let statement = "an intervening statement";
+    println!("{}", statement);
+    unsafe {
+        let raw: *const u8 = &9u8;
+        let _ = raw;
+    }
+}
@@ -48,6 +48,33 @@ fn bad_forget<T>(value: T) {
     mem::forget(value);
 }
 
+// N8: pub unsafe fn without a `# Safety` doc section (NEVER) - AST rule
+/// Reads the value at `ptr` without checking that it is valid.
+pub unsafe fn bad_missing_safety_doc(ptr: *const i32) -> i32 {
+    unsafe { *ptr }
+}
+
+// N9: transmute into a restricted-bit-pattern type (NEVER) - AST rule
+fn bad_transmute_into_bool(x: u8) -> bool {
+    unsafe { mem::transmute(x) }
+}
+
+// N10: transmute with a statically known size mismatch (NEVER) - AST rule
+fn bad_transmute_size_mismatch(bytes: [u8; 3]) -> u32 {
+    unsafe { mem::transmute(bytes) }
+}
+
+// N11: unchecked op with no guarding debug_assert/assert (SHOULD) - AST rule
+fn bad_get_unchecked(items: &[i32], index: usize) -> i32 {
+    unsafe { *items.get_unchecked(index) }
+}
+
+// PSAFE1: raw pointer field in a #[persistent] struct (NEVER) - config-driven rule
+#[persistent]
+struct BadPersistentRegion {
+    head: *const u8,
+}
+
 // S6: CamelCase for functions (SHOULD)
 fn badFunction() {
     let myValue = 42;